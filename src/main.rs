@@ -1,12 +1,19 @@
 use clap::{App, Arg};
 use colored::*;
-use flate2::write::GzEncoder;
+use flate2::read::MultiGzDecoder;
+use flate2::write::{DeflateEncoder, GzEncoder};
 use flate2::Compression;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{self, copy, BufReader, Read, Write};
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Instant;
+use tar::{Archive, Builder};
 
 #[derive(Debug)]
 enum CompressionError {
@@ -20,6 +27,15 @@ impl From<io::Error> for CompressionError {
     }
 }
 
+impl std::fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressionError::IoError(error) => write!(f, "IO error: {}", error),
+            CompressionError::InvalidInput(message) => write!(f, "{}", message),
+        }
+    }
+}
+
 struct CompressionStats {
     source_size: u64,
     target_size: u64,
@@ -27,19 +43,357 @@ struct CompressionStats {
     compression_ratio: f64,
 }
 
-fn get_compression_level(level: &str) -> Compression {
-    match level {
-        "fast" => Compression::fast(),
-        "best" => Compression::best(),
-        _ => Compression::default(),
+/// The codecs the compressor knows how to emit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompressionAlgo {
+    Gzip,
+    Zstd,
+    Brotli,
+    Xz,
+    Deflate,
+}
+
+impl CompressionAlgo {
+    /// Inclusive range of levels the codec accepts.
+    fn level_range(self) -> (u32, u32) {
+        match self {
+            CompressionAlgo::Gzip | CompressionAlgo::Deflate => (0, 9),
+            CompressionAlgo::Zstd => (1, 22),
+            CompressionAlgo::Brotli => (0, 11),
+            CompressionAlgo::Xz => (0, 9),
+        }
+    }
+
+    /// Guess the codec from a path's extension, e.g. `out.zst` -> `Zstd`.
+    /// Returns `None` when the extension is not a recognised compressed suffix.
+    fn detect_from_path(path: &str) -> Option<CompressionAlgo> {
+        match Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())?
+            .to_lowercase()
+            .as_str()
+        {
+            "gz" => Some(CompressionAlgo::Gzip),
+            "zst" => Some(CompressionAlgo::Zstd),
+            "xz" => Some(CompressionAlgo::Xz),
+            "br" => Some(CompressionAlgo::Brotli),
+            _ => None,
+        }
+    }
+
+    /// Level used when the caller does not pin one explicitly.
+    fn default_level(self) -> u32 {
+        match self {
+            CompressionAlgo::Gzip | CompressionAlgo::Deflate | CompressionAlgo::Xz => 6,
+            CompressionAlgo::Zstd => 3,
+            CompressionAlgo::Brotli => 11,
+        }
+    }
+}
+
+/// A codec paired with the level it should run at.
+#[derive(Debug, Clone, Copy)]
+struct AlgoSpec {
+    algo: CompressionAlgo,
+    level: u32,
+}
+
+/// A reader that records how many bytes have flowed through it, so stats work
+/// without a seekable source (e.g. stdin).
+struct CountingReader<R> {
+    inner: R,
+    count: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.count.fetch_add(len as u64, Ordering::Relaxed);
+        Ok(len)
+    }
+}
+
+/// A writer that records how many bytes have been written to it, used to size
+/// the output without calling `metadata().len()`.
+struct CountingWriter<W> {
+    inner: W,
+    count: Arc<AtomicU64>,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        self.count.fetch_add(len as u64, Ordering::Relaxed);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Open the source path for reading, treating `-` as stdin. Returns the reader
+/// alongside its known length (`None` for stdin, which is not seekable).
+fn open_source(source: &str) -> Result<(Box<dyn Read>, Option<u64>), CompressionError> {
+    if source == "-" {
+        return Ok((Box::new(io::stdin()), None));
+    }
+    let source_path = Path::new(source);
+    if !source_path.exists() {
+        return Err(CompressionError::InvalidInput(format!(
+            "Source file '{}' does not exist",
+            source
+        )));
+    }
+    let input_file = File::open(source_path)?;
+    let len = input_file.metadata()?.len();
+    Ok((Box::new(BufReader::new(input_file)), Some(len)))
+}
+
+/// Open the target path for writing, treating `-` as stdout.
+fn open_target(target: &str) -> Result<Box<dyn Write>, CompressionError> {
+    if target == "-" {
+        Ok(Box::new(io::stdout()))
+    } else {
+        Ok(Box::new(File::create(target)?))
     }
 }
 
+/// Parse the combined `name[/level]` syntax (e.g. `zstd/19`, `brotli`, `gzip/6`).
+///
+/// The level is validated against the codec's accepted range and falls back to
+/// the codec default when omitted.
+fn get_compression_level(spec: &str) -> Result<AlgoSpec, CompressionError> {
+    let (name, level_part) = match spec.split_once('/') {
+        Some((name, level)) => (name, Some(level)),
+        None => (spec, None),
+    };
+
+    let algo = match name.to_lowercase().as_str() {
+        "gzip" | "gz" => CompressionAlgo::Gzip,
+        "zstd" | "zst" => CompressionAlgo::Zstd,
+        "brotli" | "br" => CompressionAlgo::Brotli,
+        "xz" | "lzma2" => CompressionAlgo::Xz,
+        "deflate" => CompressionAlgo::Deflate,
+        other => {
+            return Err(CompressionError::InvalidInput(format!(
+                "Unknown compression algorithm '{}'",
+                other
+            )))
+        }
+    };
+
+    let level = match level_part {
+        Some(text) => {
+            let value: u32 = text.trim().parse().map_err(|_| {
+                CompressionError::InvalidInput(format!("Invalid compression level '{}'", text))
+            })?;
+            let (min, max) = algo.level_range();
+            if value < min || value > max {
+                return Err(CompressionError::InvalidInput(format!(
+                    "Level {} is out of range {}..={} for {:?}",
+                    value, min, max, algo
+                )));
+            }
+            value
+        }
+        None => algo.default_level(),
+    };
+
+    Ok(AlgoSpec { algo, level })
+}
+
+/// A streaming encoder finalised by an explicit, fallible `finish` rather than
+/// on `drop`, so a write error while emitting the trailer (e.g. ENOSPC) is
+/// surfaced instead of swallowed. The brotli wrapper is the exception: its
+/// `CompressorWriter` exposes no fallible close, so `finish` flushes the
+/// payload fallibly and lets `into_inner` write the tiny final marker, whose
+/// write error is discarded.
+trait Encoder: Write {
+    fn finish(self: Box<Self>) -> io::Result<()>;
+}
+
+impl<W: Write> Encoder for GzEncoder<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Encoder for DeflateEncoder<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Encoder for zstd::stream::write::Encoder<'static, W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Encoder for xz2::write::XzEncoder<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        (*self).finish()?;
+        Ok(())
+    }
+}
+
+impl<W: Write> Encoder for brotli::CompressorWriter<W> {
+    fn finish(self: Box<Self>) -> io::Result<()> {
+        // `CompressorWriter` has no fallible close. Flush the payload first so
+        // a failed write on the bulk output (e.g. ENOSPC) still propagates;
+        // `into_inner` then writes the encoder's final block, ignoring any
+        // error from that last write.
+        let mut inner = *self;
+        inner.flush()?;
+        inner.into_inner();
+        Ok(())
+    }
+}
+
+fn build_encoder<W: Write + 'static>(
+    output: W,
+    spec: AlgoSpec,
+) -> Result<Box<dyn Encoder>, CompressionError> {
+    let encoder: Box<dyn Encoder> = match spec.algo {
+        CompressionAlgo::Gzip => {
+            Box::new(GzEncoder::new(output, Compression::new(spec.level)))
+        }
+        CompressionAlgo::Deflate => {
+            Box::new(DeflateEncoder::new(output, Compression::new(spec.level)))
+        }
+        CompressionAlgo::Zstd => {
+            Box::new(zstd::stream::write::Encoder::new(output, spec.level as i32)?)
+        }
+        CompressionAlgo::Brotli => {
+            Box::new(brotli::CompressorWriter::new(output, 4096, spec.level, 22))
+        }
+        CompressionAlgo::Xz => Box::new(xz2::write::XzEncoder::new(output, spec.level)),
+    };
+    Ok(encoder)
+}
+
 fn compress_file(
     source: &str,
     target: &str,
-    compression_level: Compression,
+    spec: AlgoSpec,
     show_progress: bool,
+) -> Result<CompressionStats, CompressionError> {
+    let (reader, source_len) = open_source(source)?;
+    let source_count = Arc::new(AtomicU64::new(0));
+    let mut input = CountingReader {
+        inner: reader,
+        count: Arc::clone(&source_count),
+    };
+
+    let target_count = Arc::new(AtomicU64::new(0));
+    let counting_output = CountingWriter {
+        inner: open_target(target)?,
+        count: Arc::clone(&target_count),
+    };
+    let mut encoder = build_encoder(counting_output, spec)?;
+
+    // A seekable source has a known length and drives a byte bar; stdin does
+    // not, so fall back to a spinner rather than a misleading total.
+    let progress_bar = if show_progress {
+        let pb = match source_len {
+            Some(len) => {
+                let pb = ProgressBar::new(len);
+                pb.set_style(
+                    ProgressStyle::default_bar()
+                        .template(
+                            "[{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})",
+                        )
+                        .unwrap()
+                        .progress_chars("#>-"),
+                );
+                pb
+            }
+            None => {
+                let pb = ProgressBar::new_spinner();
+                pb.set_style(
+                    ProgressStyle::default_spinner()
+                        .template("[{elapsed_precise}] {spinner} {bytes} read")
+                        .unwrap(),
+                );
+                pb
+            }
+        };
+        Some(pb)
+    } else {
+        None
+    };
+
+    let start = Instant::now();
+
+    let mut buffer = vec![0; 1024 * 1024]; // 1MB buffer
+    loop {
+        let len = input.read(&mut buffer)?;
+        if len == 0 {
+            break;
+        }
+        encoder.write_all(&buffer[..len])?;
+        if let Some(pb) = &progress_bar {
+            pb.set_position(source_count.load(Ordering::Relaxed));
+        }
+    }
+
+    if let Some(pb) = progress_bar {
+        pb.finish_with_message("Compression complete");
+    }
+
+    // Finalise the stream explicitly so a failure while writing the codec
+    // trailer is reported rather than swallowed on drop.
+    encoder.finish()?;
+
+    // Counters stand in for `metadata().len()` so the math works on pipes too.
+    let source_size = source_count.load(Ordering::Relaxed);
+    let target_size = target_count.load(Ordering::Relaxed);
+    let compression_ratio = if source_size == 0 {
+        0.0
+    } else {
+        1.0 - (target_size as f64 / source_size as f64)
+    };
+
+    Ok(CompressionStats {
+        source_size,
+        target_size,
+        elapsed: start.elapsed(),
+        compression_ratio,
+    })
+}
+
+/// Uncompressed block size fed to each worker in the parallel gzip path.
+const PARALLEL_BLOCK_SIZE: usize = 128 * 1024;
+
+/// Fill `buffer` from `reader`, tolerating short reads, and return how many
+/// bytes were read (0 at end of input).
+fn read_block(reader: &mut impl Read, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        let len = reader.read(&mut buffer[filled..])?;
+        if len == 0 {
+            break;
+        }
+        filled += len;
+    }
+    Ok(filled)
+}
+
+/// Block-parallel gzip compression. The input is sliced into fixed-size blocks
+/// that a worker pool compresses independently; each block becomes its own gzip
+/// member, so the concatenated output is a valid multi-member gzip stream that a
+/// standard `MultiGzDecoder` reads back. Output ordering is restored with
+/// per-block sequence numbers and a reorder buffer.
+fn compress_gzip_parallel(
+    source: &str,
+    target: &str,
+    spec: AlgoSpec,
+    show_progress: bool,
+    threads: usize,
 ) -> Result<CompressionStats, CompressionError> {
     let source_path = Path::new(source);
     if !source_path.exists() {
@@ -52,9 +406,7 @@ fn compress_file(
     let input_file = File::open(source_path)?;
     let file_size = input_file.metadata()?.len();
     let mut input = BufReader::new(input_file);
-
-    let output = File::create(target)?;
-    let mut encoder = GzEncoder::new(output, compression_level);
+    let mut output = File::create(target)?;
 
     let progress_bar = if show_progress {
         let pb = ProgressBar::new(file_size);
@@ -71,31 +423,207 @@ fn compress_file(
 
     let start = Instant::now();
 
-    if let Some(pb) = &progress_bar {
-        let mut buffer = vec![0; 1024 * 1024]; // 1MB buffer
-        let mut total_read = 0u64;
+    // Bounded so the reader throttles to the worker pool instead of buffering
+    // the whole input as heap blocks when the workers fall behind.
+    let (work_tx, work_rx) = mpsc::sync_channel::<(u64, Vec<u8>)>(threads * 2);
+    let work_rx = Arc::new(Mutex::new(work_rx));
+    let (done_tx, done_rx) = mpsc::channel::<io::Result<(u64, Vec<u8>)>>();
 
+    let mut workers = Vec::with_capacity(threads);
+    for _ in 0..threads {
+        let work_rx = Arc::clone(&work_rx);
+        let done_tx = done_tx.clone();
+        let level = spec.level;
+        workers.push(thread::spawn(move || loop {
+            let job = {
+                let guard = work_rx.lock().unwrap();
+                guard.recv()
+            };
+            let (seq, block) = match job {
+                Ok(job) => job,
+                Err(_) => break,
+            };
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::new(level));
+            let member = encoder
+                .write_all(&block)
+                .and_then(|_| encoder.finish())
+                .map(|buf| (seq, buf));
+            if done_tx.send(member).is_err() {
+                break;
+            }
+        }));
+    }
+    drop(done_tx);
+
+    // Reader thread slices the input and feeds the worker pool, advancing the
+    // progress bar by bytes consumed as it goes.
+    let pb_reader = progress_bar.clone();
+    let reader = thread::spawn(move || -> io::Result<u64> {
+        let mut seq = 0u64;
+        let mut total_read = 0u64;
+        let mut buffer = vec![0u8; PARALLEL_BLOCK_SIZE];
         loop {
-            let len = input.read(&mut buffer)?;
+            let len = read_block(&mut input, &mut buffer)?;
             if len == 0 {
                 break;
             }
-            encoder.write_all(&buffer[..len])?;
             total_read += len as u64;
-            pb.set_position(total_read);
+            if let Some(pb) = &pb_reader {
+                pb.set_position(total_read);
+            }
+            if work_tx.send((seq, buffer[..len].to_vec())).is_err() {
+                break;
+            }
+            seq += 1;
+        }
+        Ok(total_read)
+    });
+
+    // Reorder the compressed members into input order before writing.
+    let mut pending: BTreeMap<u64, Vec<u8>> = BTreeMap::new();
+    let mut next = 0u64;
+    let mut write_err: Option<io::Error> = None;
+    for message in done_rx {
+        match message {
+            Ok((seq, data)) => {
+                pending.insert(seq, data);
+                while let Some(data) = pending.remove(&next) {
+                    if let Err(error) = output.write_all(&data) {
+                        write_err.get_or_insert(error);
+                    }
+                    next += 1;
+                }
+            }
+            Err(error) => {
+                write_err.get_or_insert(error);
+            }
+        }
+    }
+
+    let _total_read = reader.join().expect("reader thread panicked")?;
+    for worker in workers {
+        worker.join().expect("worker thread panicked");
+    }
+    if let Some(error) = write_err {
+        return Err(error.into());
+    }
+
+    output.flush()?;
+
+    if let Some(pb) = progress_bar {
+        pb.finish_with_message("Compression complete");
+    }
+
+    let source_size = file_size;
+    let target_size = std::fs::metadata(target)?.len();
+    let compression_ratio = if source_size == 0 {
+        0.0
+    } else {
+        1.0 - (target_size as f64 / source_size as f64)
+    };
+
+    Ok(CompressionStats {
+        source_size,
+        target_size,
+        elapsed: start.elapsed(),
+        compression_ratio,
+    })
+}
+
+/// Total size in bytes of a file, or of every regular file beneath a directory.
+fn tree_size(path: &Path) -> io::Result<u64> {
+    let meta = path.metadata()?;
+    if meta.is_dir() {
+        let mut total = 0;
+        for entry in std::fs::read_dir(path)? {
+            total += tree_size(&entry?.path())?;
         }
+        Ok(total)
     } else {
-        copy(&mut input, &mut encoder)?;
+        Ok(meta.len())
+    }
+}
+
+/// Bundle one or more inputs (files and/or directories) into a tar stream that
+/// feeds the chosen encoder, producing a standard compressed tarball. Each
+/// top-level input keeps its own name as the archive root; directories are
+/// walked recursively via `append_dir_all`.
+fn compress_archive(
+    sources: &[&str],
+    target: &str,
+    spec: AlgoSpec,
+    show_progress: bool,
+) -> Result<CompressionStats, CompressionError> {
+    for source in sources {
+        if !Path::new(source).exists() {
+            return Err(CompressionError::InvalidInput(format!(
+                "Source file '{}' does not exist",
+                source
+            )));
+        }
+    }
+
+    // Sum the uncompressed payload up front so the ratio reflects everything
+    // that went into the archive.
+    let mut source_size = 0u64;
+    for source in sources {
+        source_size += tree_size(Path::new(source))?;
+    }
+
+    let target_count = Arc::new(AtomicU64::new(0));
+    let counting_output = CountingWriter {
+        inner: open_target(target)?,
+        count: Arc::clone(&target_count),
+    };
+    let encoder = build_encoder(counting_output, spec)?;
+    let mut builder = Builder::new(encoder);
+
+    let progress_bar = if show_progress {
+        let pb = ProgressBar::new(source_size);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("[{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
+                .unwrap()
+                .progress_chars("#>-"),
+        );
+        Some(pb)
+    } else {
+        None
+    };
+
+    let start = Instant::now();
+
+    let mut added = 0u64;
+    for source in sources {
+        let path = Path::new(source);
+        let name = path
+            .file_name()
+            .map(Path::new)
+            .unwrap_or_else(|| Path::new(source));
+        if path.is_dir() {
+            builder.append_dir_all(name, path)?;
+        } else {
+            builder.append_path_with_name(path, name)?;
+        }
+        added += tree_size(path)?;
+        if let Some(pb) = &progress_bar {
+            pb.set_position(added);
+        }
     }
 
+    let encoder = builder.into_inner()?;
+    encoder.finish()?;
+
     if let Some(pb) = progress_bar {
         pb.finish_with_message("Compression complete");
     }
 
-    let output = encoder.finish()?;
-    let target_size = output.metadata()?.len();
-    let source_size = input.get_ref().metadata()?.len();
-    let compression_ratio = 1.0 - (target_size as f64 / source_size as f64);
+    let target_size = target_count.load(Ordering::Relaxed);
+    let compression_ratio = if source_size == 0 {
+        0.0
+    } else {
+        1.0 - (target_size as f64 / source_size as f64)
+    };
 
     Ok(CompressionStats {
         source_size,
@@ -105,29 +633,207 @@ fn compress_file(
     })
 }
 
+/// Pick the decoder from the source extension, mirroring `list_archive`.
+/// The multi-member gzip decoder is the default, so it also reads back the
+/// parallel path's concatenated output.
+fn build_decoder(source: &str, reader: Box<dyn Read>) -> Result<Box<dyn Read>, CompressionError> {
+    let decoder: Box<dyn Read> = match CompressionAlgo::detect_from_path(source) {
+        Some(CompressionAlgo::Zstd) => Box::new(zstd::stream::read::Decoder::new(reader)?),
+        Some(CompressionAlgo::Xz) => Box::new(xz2::read::XzDecoder::new(reader)),
+        Some(CompressionAlgo::Brotli) => Box::new(brotli::Decompressor::new(reader, 4096)),
+        _ => Box::new(MultiGzDecoder::new(reader)),
+    };
+    Ok(decoder)
+}
+
+fn decompress_file(
+    source: &str,
+    target: &str,
+    show_progress: bool,
+) -> Result<CompressionStats, CompressionError> {
+    let (reader, source_len) = open_source(source)?;
+    let source_count = Arc::new(AtomicU64::new(0));
+    let input = CountingReader {
+        inner: reader,
+        count: Arc::clone(&source_count),
+    };
+
+    let target_count = Arc::new(AtomicU64::new(0));
+    let mut output = CountingWriter {
+        inner: open_target(target)?,
+        count: Arc::clone(&target_count),
+    };
+
+    // A seekable source has a known length and drives a byte bar; stdin does
+    // not, so fall back to a spinner rather than a misleading total.
+    let progress_bar = if show_progress {
+        let pb = match source_len {
+            Some(len) => {
+                let pb = ProgressBar::new(len);
+                pb.set_style(
+                    ProgressStyle::default_bar()
+                        .template(
+                            "[{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})",
+                        )
+                        .unwrap()
+                        .progress_chars("#>-"),
+                );
+                pb
+            }
+            None => {
+                let pb = ProgressBar::new_spinner();
+                pb.set_style(
+                    ProgressStyle::default_spinner()
+                        .template("[{elapsed_precise}] {spinner} {bytes} read")
+                        .unwrap(),
+                );
+                pb
+            }
+        };
+        Some(pb)
+    } else {
+        None
+    };
+
+    let start = Instant::now();
+
+    // Drive the progress bar off the compressed input, which is the only
+    // size we know up front; the decoded output length is discovered as we go.
+    let reader: Box<dyn Read> = match &progress_bar {
+        Some(pb) => Box::new(pb.wrap_read(input)),
+        None => Box::new(input),
+    };
+    let mut decoder = build_decoder(source, reader)?;
+
+    copy(&mut decoder, &mut output).map_err(|error| match error.kind() {
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData => {
+            CompressionError::InvalidInput(format!(
+                "'{}' is not a valid compressed stream: {}",
+                source, error
+            ))
+        }
+        _ => CompressionError::IoError(error),
+    })?;
+
+    output.flush()?;
+
+    if let Some(pb) = progress_bar {
+        pb.finish_with_message("Decompression complete");
+    }
+
+    // Counters stand in for `metadata().len()` so the math works on pipes too.
+    let source_size = source_count.load(Ordering::Relaxed);
+    let target_size = target_count.load(Ordering::Relaxed);
+    let compression_ratio = if target_size == 0 {
+        0.0
+    } else {
+        1.0 - (source_size as f64 / target_size as f64)
+    };
+
+    Ok(CompressionStats {
+        source_size,
+        target_size,
+        elapsed: start.elapsed(),
+        compression_ratio,
+    })
+}
+
+/// A single entry yielded while listing a compressed tar archive.
+struct FileInArchive {
+    path: String,
+    is_dir: bool,
+}
+
+/// Stream the contents of a compressed tar archive, printing each entry as the
+/// `tar` iterator yields it rather than collecting the whole listing first.
+/// Per-entry errors are reported but do not abort the remaining entries, so a
+/// corrupt record in a huge archive still lets the rest be inspected.
+fn list_archive(source: &str) -> Result<(), CompressionError> {
+    let (reader, _len) = open_source(source)?;
+
+    // Pick the decoder from the archive extension, defaulting to gzip; the
+    // multi-member decoder also reads the parallel path's concatenated output.
+    let decoded: Box<dyn Read> = match CompressionAlgo::detect_from_path(source) {
+        Some(CompressionAlgo::Zstd) => Box::new(zstd::stream::read::Decoder::new(reader)?),
+        Some(CompressionAlgo::Xz) => Box::new(xz2::read::XzDecoder::new(reader)),
+        Some(CompressionAlgo::Brotli) => Box::new(brotli::Decompressor::new(reader, 4096)),
+        _ => Box::new(MultiGzDecoder::new(reader)),
+    };
+
+    let mut archive = Archive::new(decoded);
+    for entry in archive.entries()? {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => {
+                eprintln!(
+                    "{} {}",
+                    "Skipping unreadable entry:".bright_red(),
+                    CompressionError::from(error)
+                );
+                continue;
+            }
+        };
+
+        let is_dir = entry.header().entry_type().is_dir();
+        let path = match entry.path() {
+            Ok(path) => path.to_string_lossy().into_owned(),
+            Err(error) => {
+                eprintln!(
+                    "{} {}",
+                    "Skipping entry with bad path:".bright_red(),
+                    CompressionError::from(error)
+                );
+                continue;
+            }
+        };
+
+        let record = FileInArchive { path, is_dir };
+        let marker = if record.is_dir { "d" } else { "-" };
+        println!("{} {}", marker.bright_yellow(), record.path);
+    }
+
+    Ok(())
+}
+
 fn main() {
     let matches = App::new("File Compressor")
         .version("2.0")
         .author("kushwahramkumar2003@gmail.com")
         .about("Compresses files using GZIP compression")
         .arg(
-            Arg::new("source")
-                .help("Source file to compress")
+            Arg::new("paths")
+                .help("Input(s) to compress followed by the target, or the archive for --list")
                 .required(true)
+                .multiple_values(true)
                 .index(1),
         )
         .arg(
-            Arg::new("target")
-                .help("Target compressed file")
-                .required(true)
-                .index(2),
+            Arg::new("algo")
+                .short('a')
+                .long("algo")
+                .help("Codec and optional level, e.g. gzip, zstd/19, brotli/9, xz/6, deflate")
+                .default_value("gzip"),
+        )
+        .arg(
+            Arg::new("threads")
+                .short('t')
+                .long("threads")
+                .help("Worker threads for block-parallel gzip compression")
+                .default_value("1"),
         )
         .arg(
-            Arg::new("compression")
-                .short('c')
-                .long("compression")
-                .help("Compression level (fast, default, best)")
-                .default_value("default"),
+            Arg::new("decompress")
+                .short('d')
+                .long("decompress")
+                .help("Decompress the source instead of compressing it")
+                .takes_value(false),
+        )
+        .arg(
+            Arg::new("list")
+                .short('l')
+                .long("list")
+                .help("List the contents of a compressed tar archive")
+                .takes_value(false),
         )
         .arg(
             Arg::new("quiet")
@@ -138,37 +844,142 @@ fn main() {
         )
         .get_matches();
 
-    let source = matches.value_of("source").unwrap();
-    let target = matches.value_of("target").unwrap();
-    let compression_level = get_compression_level(matches.value_of("compression").unwrap());
+    let paths: Vec<&str> = matches.values_of("paths").unwrap().collect();
     let show_progress = !matches.is_present("quiet");
 
-    println!("{}", "\nFile Compression Utility".bright_green().bold());
-    println!("{}", "=======================".bright_green());
+    // `--list` only needs the archive path and prints entries as it streams.
+    if matches.is_present("list") {
+        println!("{}", "\nArchive Contents".bright_green().bold());
+        println!("{}", "================".bright_green());
+        if let Err(error) = list_archive(paths[0]) {
+            eprintln!("\n{} {}", "Error:".bright_red().bold(), error);
+            std::process::exit(1);
+        }
+        return;
+    }
+    let algo_explicit = matches.occurrences_of("algo") > 0;
+    let mut decompress = matches.is_present("decompress");
+
+    // Every positional but the last is an input; the final one is the target.
+    if paths.len() < 2 {
+        eprintln!(
+            "\n{} {}",
+            "Error:".bright_red().bold(),
+            "expected at least one input and a target path"
+        );
+        std::process::exit(1);
+    }
+    let sources = &paths[..paths.len() - 1];
+    let target = *paths.last().unwrap();
+
+    // With no explicit --algo, a single compressed-looking source means "undo it".
+    if !algo_explicit
+        && !decompress
+        && sources.len() == 1
+        && CompressionAlgo::detect_from_path(sources[0]).is_some()
+    {
+        decompress = true;
+    }
+
+    // Diagnostics go to stderr so `<input> -` keeps stdout a clean data stream.
+    eprintln!("{}", "\nFile Compression Utility".bright_green().bold());
+    eprintln!("{}", "=======================".bright_green());
 
-    match compress_file(source, target, compression_level, show_progress) {
+    let threads = match matches.value_of("threads").unwrap().parse::<usize>() {
+        Ok(value) if value >= 1 => value,
+        _ => {
+            eprintln!(
+                "\n{} {}",
+                "Error:".bright_red().bold(),
+                "--threads must be a positive integer"
+            );
+            std::process::exit(1);
+        }
+    };
+
+    // Decompression handles a single source; refuse to silently drop extras.
+    if decompress && sources.len() > 1 {
+        eprintln!(
+            "\n{} {}",
+            "Error:".bright_red().bold(),
+            "--decompress accepts a single source"
+        );
+        std::process::exit(1);
+    }
+
+    let result = if decompress {
+        decompress_file(sources[0], target, show_progress)
+    } else {
+        // An explicit --algo always wins; otherwise guess the codec from the
+        // target extension and fall back to gzip.
+        let spec = if algo_explicit {
+            get_compression_level(matches.value_of("algo").unwrap())
+        } else if let Some(algo) = CompressionAlgo::detect_from_path(target) {
+            Ok(AlgoSpec {
+                algo,
+                level: algo.default_level(),
+            })
+        } else {
+            get_compression_level("gzip")
+        };
+
+        // Multiple inputs or a directory become a tar archive; a single file
+        // stays a plain compressed stream.
+        let archive_mode =
+            sources.len() > 1 || sources.iter().any(|s| Path::new(s).is_dir());
+
+        match spec {
+            Ok(spec) if archive_mode => compress_archive(sources, target, spec, show_progress),
+            Ok(spec)
+                if threads > 1
+                    && spec.algo == CompressionAlgo::Gzip
+                    && sources[0] != "-"
+                    && target != "-" =>
+            {
+                compress_gzip_parallel(sources[0], target, spec, show_progress, threads)
+            }
+            Ok(spec) => compress_file(sources[0], target, spec, show_progress),
+            Err(error) => Err(error),
+        }
+    };
+
+    match result {
         Ok(stats) => {
-            println!("\n{}", "Compression Summary:".bright_blue().bold());
-            println!(
+            // In decompress mode the output is the *uncompressed* payload, so
+            // swap the labels rather than calling it a "Compressed size".
+            let (title, size_label, ratio_label, done) = if decompress {
+                (
+                    "Decompression Summary:",
+                    "Decompressed size",
+                    "Space saved",
+                    "Decompression completed successfully!",
+                )
+            } else {
+                (
+                    "Compression Summary:",
+                    "Compressed size",
+                    "Compression ratio",
+                    "Compression completed successfully!",
+                )
+            };
+            eprintln!("\n{}", title.bright_blue().bold());
+            eprintln!(
                 "{}: {:.2} MB",
                 "Source file size".bright_yellow(),
                 stats.source_size as f64 / 1_048_576.0
             );
-            println!(
+            eprintln!(
                 "{}: {}",
-                "Compressed size".bright_yellow(),
+                size_label.bright_yellow(),
                 stats.target_size as f64 / 1_048_576.0
             );
-            println!(
+            eprintln!(
                 "{}: {:.1}%",
-                "Compression ratio".bright_yellow(),
+                ratio_label.bright_yellow(),
                 stats.compression_ratio * 100.0
             );
-            println!("{}: {:.2?}", "Time elapsed".bright_yellow(), stats.elapsed);
-            println!(
-                "\n{}\n",
-                "Compression completed successfully!".bright_green().bold()
-            );
+            eprintln!("{}: {:.2?}", "Time elapsed".bright_yellow(), stats.elapsed);
+            eprintln!("\n{}\n", done.bright_green().bold());
         }
         Err(error) => {
             eprintln!(
@@ -183,3 +994,56 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_algo_aliases() {
+        assert_eq!(get_compression_level("gz").unwrap().algo, CompressionAlgo::Gzip);
+        assert_eq!(get_compression_level("zstd").unwrap().algo, CompressionAlgo::Zstd);
+        assert_eq!(get_compression_level("zst").unwrap().algo, CompressionAlgo::Zstd);
+        assert_eq!(get_compression_level("brotli").unwrap().algo, CompressionAlgo::Brotli);
+        assert_eq!(get_compression_level("lzma2").unwrap().algo, CompressionAlgo::Xz);
+        assert_eq!(get_compression_level("deflate").unwrap().algo, CompressionAlgo::Deflate);
+    }
+
+    #[test]
+    fn defaults_level_when_omitted() {
+        let spec = get_compression_level("zstd").unwrap();
+        assert_eq!(spec.level, 3);
+        let spec = get_compression_level("gzip").unwrap();
+        assert_eq!(spec.level, 6);
+    }
+
+    #[test]
+    fn parses_explicit_level() {
+        let spec = get_compression_level("zstd/19").unwrap();
+        assert_eq!(spec.algo, CompressionAlgo::Zstd);
+        assert_eq!(spec.level, 19);
+    }
+
+    #[test]
+    fn rejects_out_of_range_level() {
+        assert!(get_compression_level("gzip/10").is_err());
+        assert!(get_compression_level("zstd/0").is_err());
+        assert!(get_compression_level("brotli/12").is_err());
+    }
+
+    #[test]
+    fn rejects_non_numeric_and_unknown() {
+        assert!(get_compression_level("gzip/x").is_err());
+        assert!(get_compression_level("lz4").is_err());
+    }
+
+    #[test]
+    fn detects_codec_from_extension() {
+        assert_eq!(CompressionAlgo::detect_from_path("out.gz"), Some(CompressionAlgo::Gzip));
+        assert_eq!(CompressionAlgo::detect_from_path("out.zst"), Some(CompressionAlgo::Zstd));
+        assert_eq!(CompressionAlgo::detect_from_path("out.xz"), Some(CompressionAlgo::Xz));
+        assert_eq!(CompressionAlgo::detect_from_path("a.TAR.BR"), Some(CompressionAlgo::Brotli));
+        assert_eq!(CompressionAlgo::detect_from_path("plain.txt"), None);
+        assert_eq!(CompressionAlgo::detect_from_path("noext"), None);
+    }
+}